@@ -0,0 +1,266 @@
+//! OCSP request builder
+
+use crate::{
+    ext::AcceptableResponses, CertId, Error, OcspRequest, Request, Result, Signature, TbsRequest,
+    Version,
+};
+use alloc::vec::Vec;
+use const_oid::AssociatedOid;
+use der::Encode;
+use signature::Signer;
+use spki::{DynSignatureAlgorithmIdentifier, SignatureBitStringEncoding};
+use x509_cert::{
+    certificate::Certificate,
+    ext::{pkix::name::GeneralName, Extension, Extensions},
+};
+
+#[cfg(feature = "rand")]
+use crate::ext::Nonce;
+#[cfg(feature = "rand")]
+use rand_core::RngCore;
+
+/// A builder for assembling a [`TbsRequest`] (and, once signed, an [`OcspRequest`]) one piece
+/// at a time: push `CertId` entries onto the request list, optionally set a `requestorName` and
+/// extensions, then call [`build`](Self::build) or [`sign`](Self::sign).
+///
+/// ```no_run
+/// use const_oid::db::rfc5912::ID_SHA_1;
+/// use der::asn1::OctetString;
+/// use spki::AlgorithmIdentifierOwned;
+/// use x509_cert::serial_number::SerialNumber;
+/// use x509_ocsp::{builder::OcspRequestBuilder, CertId};
+///
+/// let cert_id = CertId {
+///     hash_algorithm: AlgorithmIdentifierOwned {
+///         oid: ID_SHA_1,
+///         parameters: None,
+///     },
+///     issuer_name_hash: OctetString::new(vec![0u8; 20])?,
+///     issuer_key_hash: OctetString::new(vec![0u8; 20])?,
+///     serial_number: SerialNumber::new(&[0x01])?,
+/// };
+///
+/// let tbs_request = OcspRequestBuilder::new().add_cert(cert_id).build();
+/// # Ok::<(), der::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OcspRequestBuilder {
+    version: Version,
+    requestor_name: Option<GeneralName>,
+    request_list: Vec<Request>,
+    request_extensions: Option<Extensions>,
+}
+
+impl OcspRequestBuilder {
+    /// Creates a new builder with `version` defaulted to v1 and no requests.
+    pub fn new() -> Self {
+        Self {
+            version: Version::default(),
+            requestor_name: None,
+            request_list: Vec::new(),
+            request_extensions: None,
+        }
+    }
+
+    /// Overrides the default (v1) `version`.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the optional `requestorName`.
+    pub fn with_requestor_name(mut self, requestor_name: GeneralName) -> Self {
+        self.requestor_name = Some(requestor_name);
+        self
+    }
+
+    /// Appends a [`CertId`] to the request list with no `singleRequestExtensions`.
+    pub fn add_cert(self, cert_id: CertId) -> Self {
+        self.add_cert_with_extensions(cert_id, None)
+    }
+
+    /// Appends a [`CertId`] to the request list along with its `singleRequestExtensions`.
+    pub fn add_cert_with_extensions(
+        mut self,
+        cert_id: CertId,
+        single_request_extensions: Option<Extensions>,
+    ) -> Self {
+        self.request_list.push(Request {
+            req_cert: cert_id,
+            single_request_extensions,
+        });
+        self
+    }
+
+    /// Sets the top-level `requestExtensions`.
+    pub fn with_request_extensions(mut self, request_extensions: Extensions) -> Self {
+        self.request_extensions = Some(request_extensions);
+        self
+    }
+
+    /// Appends `extension` to the top-level `requestExtensions`.
+    fn push_request_extension(&mut self, extension: Extension) {
+        self.request_extensions
+            .get_or_insert_with(Extensions::new)
+            .push(extension);
+    }
+
+    /// Generates a random nonce with `rng` and attaches it as an RFC 8954 `Nonce` extension
+    /// (`critical = false`) on `requestExtensions`.
+    ///
+    /// `length` is the number of random octets to use for the nonce, clamped to the
+    /// `[1, 32]` octet range recommended by [RFC 8954 Section 2.1]; pass
+    /// [`crate::ext::NONCE_DEFAULT_LENGTH`] for the default of 32 octets.
+    ///
+    /// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn with_nonce(mut self, rng: impl RngCore, length: usize) -> Result<Self> {
+        let nonce = Nonce::generate(rng, length)?;
+        let extension = Extension {
+            extn_id: Nonce::OID,
+            critical: false,
+            extn_value: der::asn1::OctetString::new(nonce.to_der()?)?,
+        };
+        self.push_request_extension(extension);
+        Ok(self)
+    }
+
+    /// Attaches an `AcceptableResponses` extension (`critical = false`) advertising the
+    /// `ResponseData` formats (as `OBJECT IDENTIFIER`s, e.g. `id-pkix-ocsp-basic`) this client
+    /// is able to parse.
+    pub fn with_acceptable_responses(
+        mut self,
+        acceptable_responses: AcceptableResponses,
+    ) -> Result<Self> {
+        let extension = Extension {
+            extn_id: AcceptableResponses::OID,
+            critical: false,
+            extn_value: der::asn1::OctetString::new(acceptable_responses.to_der()?)?,
+        };
+        self.push_request_extension(extension);
+        Ok(self)
+    }
+
+    /// Builds the unsigned [`TbsRequest`].
+    pub fn build(self) -> TbsRequest {
+        TbsRequest {
+            version: self.version,
+            requestor_name: self.requestor_name,
+            request_list: self.request_list,
+            request_extensions: self.request_extensions,
+        }
+    }
+
+    /// Builds the [`TbsRequest`], DER-encodes it, signs it with `signer`, and wraps the result
+    /// (together with the optional `certs` chain) in the `optionalSignature` field of an
+    /// [`OcspRequest`].
+    pub fn sign<S, Sig>(self, signer: &S, certs: Option<Vec<Certificate>>) -> Result<OcspRequest>
+    where
+        S: DynSignatureAlgorithmIdentifier,
+        S: Signer<Sig>,
+        Sig: SignatureBitStringEncoding,
+    {
+        let tbs_request = self.build();
+        let tbs_request_der = tbs_request.to_der()?;
+
+        let signature_algorithm = signer
+            .signature_algorithm_identifier()
+            .map_err(|_| Error::UnsupportedAlgorithm)?;
+        let signature = signer
+            .try_sign(&tbs_request_der)
+            .map_err(|_| Error::SigningFailed)?
+            .to_bitstring()?;
+
+        Ok(OcspRequest {
+            tbs_request,
+            optional_signature: Some(Signature {
+                signature_algorithm,
+                signature,
+                certs,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OcspRequestBuilder;
+    use crate::ext::AcceptableResponses;
+    use alloc::vec;
+    use const_oid::db::rfc6960::ID_PKIX_OCSP_BASIC;
+
+    #[test]
+    fn acceptable_responses_round_trip() {
+        let acceptable_responses = AcceptableResponses(vec![ID_PKIX_OCSP_BASIC]);
+        let tbs_request = OcspRequestBuilder::new()
+            .with_acceptable_responses(acceptable_responses.clone())
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            tbs_request.acceptable_responses(),
+            Ok(Some(acceptable_responses))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn with_nonce_round_trip() {
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+
+        let rng = ChaCha8Rng::seed_from_u64(1);
+        let tbs_request = OcspRequestBuilder::new()
+            .with_nonce(rng, 32)
+            .unwrap()
+            .build();
+
+        let nonce = tbs_request.nonce().expect("nonce extension");
+        assert_eq!(nonce.0.as_bytes().len(), 32);
+    }
+
+    #[cfg(feature = "verify")]
+    fn sample_cert_id() -> crate::CertId {
+        use const_oid::db::rfc5912::ID_SHA_1;
+        use der::asn1::OctetString;
+        use spki::AlgorithmIdentifierOwned;
+        use x509_cert::serial_number::SerialNumber;
+
+        crate::CertId {
+            hash_algorithm: AlgorithmIdentifierOwned {
+                oid: ID_SHA_1,
+                parameters: None,
+            },
+            issuer_name_hash: OctetString::new(vec![0u8; 20]).unwrap(),
+            issuer_key_hash: OctetString::new(vec![0u8; 20]).unwrap(),
+            serial_number: SerialNumber::new(&[0x01]).unwrap(),
+        }
+    }
+
+    #[cfg(feature = "verify")]
+    #[test]
+    fn sign_round_trips_with_verify_signature() {
+        use der::Decode;
+        use p256::ecdsa::{DerSignature, SigningKey};
+        use pkcs8::EncodePublicKey;
+        use rand_chacha::ChaCha8Rng;
+        use rand_core::SeedableRng;
+        use spki::SubjectPublicKeyInfoOwned;
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let signing_key = SigningKey::random(&mut rng);
+        let spki_der = signing_key
+            .verifying_key()
+            .to_public_key_der()
+            .expect("spki encoding");
+        let spki = SubjectPublicKeyInfoOwned::from_der(spki_der.as_bytes()).expect("spki decode");
+
+        let request = OcspRequestBuilder::new()
+            .add_cert(sample_cert_id())
+            .sign::<_, DerSignature>(&signing_key, None)
+            .expect("signing");
+
+        assert_eq!(request.verify_signature(Some(&spki)), Ok(()));
+    }
+}
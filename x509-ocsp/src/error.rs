@@ -0,0 +1,62 @@
+//! Error types
+
+use core::fmt;
+
+/// Result type for the `x509-ocsp` crate, with the [`Error`] type as the error variant.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type for the `x509-ocsp` crate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// DER encoding/decoding error.
+    Asn1(der::Error),
+
+    /// An extension with the requested OID was present more than once.
+    DuplicateExtension,
+
+    /// The `OCSPRequest` has no `optionalSignature` to verify.
+    MissingSignature,
+
+    /// No public key was available to verify the signature against.
+    MissingPublicKey,
+
+    /// A public key was available but failed to parse as the key type the claimed
+    /// `signatureAlgorithm` requires.
+    InvalidPublicKey,
+
+    /// The signature algorithm OID is not supported.
+    UnsupportedAlgorithm,
+
+    /// Signature verification failed.
+    InvalidSignature,
+
+    /// The signer failed to produce a signature.
+    SigningFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "ASN.1 error: {err}"),
+            Error::DuplicateExtension => write!(f, "duplicate extension"),
+            Error::MissingSignature => write!(f, "OCSP request has no signature to verify"),
+            Error::MissingPublicKey => write!(f, "no public key available to verify signature"),
+            Error::InvalidPublicKey => {
+                write!(f, "public key does not match claimed signature algorithm")
+            }
+            Error::UnsupportedAlgorithm => write!(f, "unsupported signature algorithm"),
+            Error::InvalidSignature => write!(f, "signature verification failed"),
+            Error::SigningFailed => write!(f, "signer failed to produce a signature"),
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        Error::Asn1(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
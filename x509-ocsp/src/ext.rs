@@ -0,0 +1,165 @@
+//! OCSP request/response extensions
+
+use alloc::vec::Vec;
+use const_oid::db::rfc6960::{ID_PKIX_OCSP_NONCE, ID_PKIX_OCSP_RESPONSE};
+use const_oid::{AssociatedOid, ObjectIdentifier};
+use der::asn1::OctetString;
+use x509_cert::impl_newtype;
+
+#[cfg(feature = "rand")]
+use alloc::vec;
+#[cfg(feature = "rand")]
+use rand_core::RngCore;
+
+/// Minimum nonce length, in octets, recommended by [RFC 8954 Section 2.1].
+///
+/// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+pub const NONCE_MIN_LENGTH: usize = 1;
+
+/// Maximum nonce length, in octets, recommended by [RFC 8954 Section 2.1].
+///
+/// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+pub const NONCE_MAX_LENGTH: usize = 32;
+
+/// Default nonce length, in octets, to pass as the `length` argument of [`Nonce::generate`].
+pub const NONCE_DEFAULT_LENGTH: usize = 32;
+
+/// Nonce extension as defined in [RFC 8954 Section 2.1].
+///
+/// ```text
+/// Nonce ::= OCTET STRING
+/// ```
+///
+/// [RFC 8954 Section 2.1]: https://datatracker.ietf.org/doc/html/rfc8954#section-2.1
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Nonce(pub OctetString);
+
+impl_newtype!(Nonce, OctetString);
+
+impl AssociatedOid for Nonce {
+    const OID: ObjectIdentifier = ID_PKIX_OCSP_NONCE;
+}
+
+impl Nonce {
+    /// Generates a random [`Nonce`] using `rng`, per the recommendations of
+    /// [RFC 8954 Section 2.1].
+    ///
+    /// `length` is the number of random octets to generate, clamped to the
+    /// `[`[`NONCE_MIN_LENGTH`]`, `[`NONCE_MAX_LENGTH`]`]` range.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn generate(mut rng: impl RngCore, length: usize) -> der::Result<Self> {
+        let length = length.clamp(NONCE_MIN_LENGTH, NONCE_MAX_LENGTH);
+        let mut bytes = vec![0u8; length];
+        rng.fill_bytes(&mut bytes);
+        Ok(Self(OctetString::new(bytes)?))
+    }
+
+    /// Compares this nonce against `other` in constant time, as recommended when matching a
+    /// response's nonce against the one sent in the original request (see
+    /// [RFC 8954 Section 3]).
+    ///
+    /// Returns `false` if the two nonces are not the same length, including when either is
+    /// shorter than [`NONCE_MIN_LENGTH`].
+    ///
+    /// [RFC 8954 Section 3]: https://datatracker.ietf.org/doc/html/rfc8954#section-3
+    pub fn matches_nonce(&self, other: &Nonce) -> bool {
+        use subtle::ConstantTimeEq;
+
+        let a = self.0.as_bytes();
+        let b = other.0.as_bytes();
+
+        if a.len() < NONCE_MIN_LENGTH || a.len() != b.len() {
+            return false;
+        }
+
+        a.ct_eq(b).into()
+    }
+}
+
+/// AcceptableResponses extension as defined in [RFC 6960 Section 4.4.3].
+///
+/// Lets a client advertise which `ResponseData` formats it is able to parse.
+///
+/// ```text
+/// AcceptableResponses ::= SEQUENCE OF OBJECT IDENTIFIER
+/// ```
+///
+/// [RFC 6960 Section 4.4.3]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.4.3
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcceptableResponses(pub Vec<ObjectIdentifier>);
+
+impl_newtype!(AcceptableResponses, Vec<ObjectIdentifier>);
+
+impl AssociatedOid for AcceptableResponses {
+    const OID: ObjectIdentifier = ID_PKIX_OCSP_RESPONSE;
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use super::{Nonce, NONCE_MAX_LENGTH, NONCE_MIN_LENGTH};
+    use rand_core::RngCore;
+
+    /// A fixed-byte RNG so tests are deterministic without pulling in an OS RNG dependency.
+    struct FixedRng(u8);
+
+    impl RngCore for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            self.0 as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 as u64
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(self.0);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn generate_clamps_length() {
+        assert_eq!(
+            Nonce::generate(FixedRng(0x42), 0).unwrap().0.as_bytes().len(),
+            NONCE_MIN_LENGTH
+        );
+        assert_eq!(
+            Nonce::generate(FixedRng(0x42), 1000)
+                .unwrap()
+                .0
+                .as_bytes()
+                .len(),
+            NONCE_MAX_LENGTH
+        );
+        assert_eq!(
+            Nonce::generate(FixedRng(0x42), 16).unwrap().0.as_bytes().len(),
+            16
+        );
+    }
+
+    #[test]
+    fn matches_nonce_same_value() {
+        let a = Nonce::generate(FixedRng(0x01), 32).unwrap();
+        let b = Nonce::generate(FixedRng(0x01), 32).unwrap();
+        assert!(a.matches_nonce(&b));
+    }
+
+    #[test]
+    fn matches_nonce_different_value() {
+        let a = Nonce::generate(FixedRng(0x01), 32).unwrap();
+        let b = Nonce::generate(FixedRng(0x02), 32).unwrap();
+        assert!(!a.matches_nonce(&b));
+    }
+
+    #[test]
+    fn matches_nonce_different_length() {
+        let a = Nonce::generate(FixedRng(0x01), 32).unwrap();
+        let b = Nonce::generate(FixedRng(0x01), 16).unwrap();
+        assert!(!a.matches_nonce(&b));
+    }
+}
@@ -0,0 +1,153 @@
+//! HTTP transport encoding helpers for [`OcspRequest`], per [RFC 6960 Appendix A.1].
+//!
+//! [RFC 6960 Appendix A.1]: https://datatracker.ietf.org/doc/html/rfc6960#appendix-A.1
+
+use crate::{Error, OcspRequest, Result};
+use alloc::{string::String, vec::Vec};
+use base64ct::{Base64, Encoding};
+use der::Encode;
+
+#[cfg(feature = "pem")]
+use der::pem::PemLabel;
+
+/// The `Content-Type` used when POSTing an [`OcspRequest`], per [RFC 6960 Appendix A.1].
+///
+/// [RFC 6960 Appendix A.1]: https://datatracker.ietf.org/doc/html/rfc6960#appendix-A.1
+pub const OCSP_REQUEST_CONTENT_TYPE: &str = "application/ocsp-request";
+
+impl OcspRequest {
+    /// DER-encodes the request, base64-encodes it, and percent-encodes the result into the URL
+    /// path segment used by the OCSP-over-GET binding of [RFC 6960 Appendix A.1]: clients form
+    /// the full request URL by appending `"/" + request.to_http_get_path()?` to the OCSP
+    /// responder's base URL.
+    ///
+    /// [RFC 6960 Appendix A.1]: https://datatracker.ietf.org/doc/html/rfc6960#appendix-A.1
+    pub fn to_http_get_path(&self) -> Result<String> {
+        let der = self.to_der()?;
+        let base64 = Base64::encode_string(&der);
+        Ok(percent_encode_path_segment(&base64))
+    }
+
+    /// DER-encodes the request for use as the body of an OCSP-over-POST request. Pair this with
+    /// the [`OCSP_REQUEST_CONTENT_TYPE`] `Content-Type` header, per
+    /// [RFC 6960 Appendix A.1].
+    ///
+    /// [RFC 6960 Appendix A.1]: https://datatracker.ietf.org/doc/html/rfc6960#appendix-A.1
+    pub fn to_http_post_body(&self) -> Result<Vec<u8>> {
+        self.to_der().map_err(Error::from)
+    }
+}
+
+#[cfg(feature = "pem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+impl PemLabel for OcspRequest {
+    const PEM_LABEL: &'static str = "OCSP REQUEST";
+}
+
+/// Percent-encodes the octets of `base64` that are not allowed unescaped in a URL path segment.
+///
+/// The base64 alphabet only ever produces `+`, `/`, and `=` outside of
+/// `[A-Za-z0-9]`, so those are the only octets that need escaping here.
+fn percent_encode_path_segment(base64: &str) -> String {
+    let mut encoded = String::with_capacity(base64.len());
+
+    for byte in base64.bytes() {
+        match byte {
+            b'+' => encoded.push_str("%2B"),
+            b'/' => encoded.push_str("%2F"),
+            b'=' => encoded.push_str("%3D"),
+            _ => encoded.push(byte as char),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{OcspRequest, TbsRequest};
+    use alloc::{string::String, vec::Vec};
+    use base64ct::{Base64, Encoding};
+    use der::Encode;
+
+    fn sample_request() -> OcspRequest {
+        OcspRequest {
+            tbs_request: TbsRequest {
+                version: 0,
+                requestor_name: None,
+                request_list: Vec::new(),
+                request_extensions: None,
+            },
+            optional_signature: None,
+        }
+    }
+
+    fn percent_decode(encoded: &str) -> String {
+        let mut decoded = String::with_capacity(encoded.len());
+        let mut bytes = encoded.bytes();
+
+        while let Some(byte) = bytes.next() {
+            if byte == b'%' {
+                let hi = bytes.next().expect("percent-escape high nibble");
+                let lo = bytes.next().expect("percent-escape low nibble");
+                let hex = [hi, lo];
+                let hex = core::str::from_utf8(&hex).expect("ascii hex");
+                let value = u8::from_str_radix(hex, 16).expect("valid hex");
+                decoded.push(value as char);
+            } else {
+                decoded.push(byte as char);
+            }
+        }
+
+        decoded
+    }
+
+    #[test]
+    fn to_http_get_path_round_trips_to_der() {
+        let request = sample_request();
+        let der = request.to_der().expect("der encoding");
+
+        let path = request.to_http_get_path().expect("get path");
+        let base64 = percent_decode(&path);
+        let decoded = Base64::decode_vec(&base64).expect("base64 decoding");
+
+        assert_eq!(decoded, der);
+    }
+
+    #[test]
+    fn to_http_get_path_escapes_reserved_characters() {
+        let path = sample_request().to_http_get_path().expect("get path");
+        assert!(!path.contains('+'));
+        assert!(!path.contains('/'));
+        assert!(!path.contains('='));
+
+        // The sample request's base64 form has none of `+`, `/`, `=`, so exercise the escaping
+        // directly as well.
+        assert_eq!(
+            super::percent_encode_path_segment("ab+/cd=="),
+            "ab%2B%2Fcd%3D%3D"
+        );
+    }
+
+    #[test]
+    fn to_http_post_body_matches_der() {
+        let request = sample_request();
+        assert_eq!(
+            request.to_http_post_body().expect("post body"),
+            request.to_der().expect("der encoding")
+        );
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn pem_round_trips_to_der() {
+        use der::pem::LineEnding;
+        use der::{DecodePem, EncodePem};
+
+        let request = sample_request();
+        let pem = request.to_pem(LineEnding::LF).expect("pem encoding");
+        let decoded = OcspRequest::from_pem(&pem).expect("pem decoding");
+
+        assert_eq!(decoded, request);
+    }
+}
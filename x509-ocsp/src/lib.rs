@@ -0,0 +1,65 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![forbid(unsafe_code)]
+#![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
+
+//! # `x509-ocsp`
+//!
+//! Pure Rust implementation of the Online Certificate Status Protocol (OCSP) as described in
+//! [RFC 6960].
+//!
+//! [RFC 6960]: https://datatracker.ietf.org/doc/html/rfc6960
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "builder")]
+#[cfg_attr(docsrs, doc(cfg(feature = "builder")))]
+pub mod builder;
+
+pub mod ext;
+
+mod error;
+mod http;
+mod request;
+
+#[cfg(feature = "verify")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verify")))]
+mod verify;
+
+pub use crate::error::{Error, Result};
+pub use crate::http::OCSP_REQUEST_CONTENT_TYPE;
+pub use crate::request::{check_duplicate_extensions, OcspRequest, Request, Signature, TbsRequest};
+
+use der::asn1::OctetString;
+use spki::AlgorithmIdentifierOwned;
+use x509_cert::serial_number::SerialNumber;
+
+/// Version enumerates the supported versions of OCSP request/response.
+///
+/// ```text
+/// Version ::= INTEGER { v1(0) }
+/// ```
+pub type Version = u8;
+
+/// CertID structure as defined in [RFC 6960 Section 4.1.1].
+///
+/// ```text
+/// CertID ::= SEQUENCE {
+///    hashAlgorithm       AlgorithmIdentifier,
+///    issuerNameHash      OCTET STRING,
+///    issuerKeyHash       OCTET STRING,
+///    serialNumber        CertificateSerialNumber }
+/// ```
+///
+/// [RFC 6960 Section 4.1.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.1.1
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+#[allow(missing_docs)]
+pub struct CertId {
+    pub hash_algorithm: AlgorithmIdentifierOwned,
+    pub issuer_name_hash: OctetString,
+    pub issuer_key_hash: OctetString,
+    pub serial_number: SerialNumber,
+}
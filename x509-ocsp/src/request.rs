@@ -1,8 +1,11 @@
 //! OCSP Request
 
-use crate::{ext::Nonce, CertId, Version};
+use crate::{
+    ext::{AcceptableResponses, Nonce},
+    CertId, Error, Result, Version,
+};
 use alloc::vec::Vec;
-use const_oid::db::rfc6960::ID_PKIX_OCSP_NONCE;
+use const_oid::AssociatedOid;
 use core::{default::Default, option::Option};
 use der::{asn1::BitString, Decode, Sequence};
 use spki::AlgorithmIdentifierOwned;
@@ -11,6 +14,42 @@ use x509_cert::{
     ext::{pkix::name::GeneralName, Extensions},
 };
 
+/// Finds and decodes the extension with OID `T::OID` within `extensions`.
+///
+/// Returns `Ok(None)` if no extension with that OID is present, and
+/// [`Error::DuplicateExtension`] if more than one is present.
+fn decode_extension<T>(extensions: &Option<Extensions>) -> Result<Option<T>>
+where
+    T: AssociatedOid + for<'a> Decode<'a>,
+{
+    let extensions = match extensions {
+        Some(extensions) => extensions,
+        None => return Ok(None),
+    };
+
+    let mut matches = extensions.iter().filter(|extn| extn.extn_id == T::OID);
+    let extn = match matches.next() {
+        Some(extn) => extn,
+        None => return Ok(None),
+    };
+    if matches.next().is_some() {
+        return Err(Error::DuplicateExtension);
+    }
+
+    Ok(Some(T::from_der(extn.extn_value.as_bytes())?))
+}
+
+/// Checks that no two extensions in `extensions` share an OID.
+pub fn check_duplicate_extensions(extensions: &Extensions) -> Result<()> {
+    for (i, extn) in extensions.iter().enumerate() {
+        if extensions[..i].iter().any(|other| other.extn_id == extn.extn_id) {
+            return Err(Error::DuplicateExtension);
+        }
+    }
+
+    Ok(())
+}
+
 /// OCSPRequest structure as defined in [RFC 6960 Section 4.1.1].
 ///
 /// ```text
@@ -68,19 +107,26 @@ pub struct TbsRequest {
 }
 
 impl TbsRequest {
+    /// Finds and decodes the `requestExtensions` entry with OID `T::OID`.
+    ///
+    /// Returns `Ok(None)` if no such extension is present, and
+    /// [`Error::DuplicateExtension`] if more than one is present.
+    pub fn get_extension<T>(&self) -> Result<Option<T>>
+    where
+        T: AssociatedOid + for<'a> Decode<'a>,
+    {
+        decode_extension(&self.request_extensions)
+    }
+
     /// Returns the request's nonce value, if any. This method will return `None` if the request
     /// has no `Nonce` extension or decoding of the `Nonce` extension fails.
     pub fn nonce(&self) -> Option<Nonce> {
-        match &self.request_extensions {
-            Some(extns) => {
-                let mut filter = extns.iter().filter(|e| e.extn_id == ID_PKIX_OCSP_NONCE);
-                match filter.next() {
-                    Some(extn) => Nonce::from_der(extn.extn_value.as_bytes()).ok(),
-                    None => None,
-                }
-            }
-            None => None,
-        }
+        self.get_extension::<Nonce>().ok().flatten()
+    }
+
+    /// Returns the request's `AcceptableResponses` extension, if any.
+    pub fn acceptable_responses(&self) -> Result<Option<AcceptableResponses>> {
+        self.get_extension::<AcceptableResponses>()
     }
 }
 
@@ -121,3 +167,138 @@ pub struct Request {
     #[asn1(context_specific = "0", optional = "true", tag_mode = "EXPLICIT")]
     pub single_request_extensions: Option<Extensions>,
 }
+
+impl Request {
+    /// Finds and decodes the `singleRequestExtensions` entry with OID `T::OID`.
+    ///
+    /// Returns `Ok(None)` if no such extension is present, and
+    /// [`Error::DuplicateExtension`] if more than one is present.
+    pub fn get_extension<T>(&self) -> Result<Option<T>>
+    where
+        T: AssociatedOid + for<'a> Decode<'a>,
+    {
+        decode_extension(&self.single_request_extensions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use der::{asn1::OctetString, Encode};
+    use x509_cert::ext::Extension;
+
+    fn nonce_extension(byte: u8) -> Extension {
+        let nonce = Nonce(OctetString::new(vec![byte; 4]).unwrap());
+        Extension {
+            extn_id: Nonce::OID,
+            critical: false,
+            extn_value: OctetString::new(nonce.to_der().unwrap()).unwrap(),
+        }
+    }
+
+    fn acceptable_responses_extension() -> Extension {
+        let acceptable_responses =
+            AcceptableResponses(vec![const_oid::db::rfc6960::ID_PKIX_OCSP_BASIC]);
+        Extension {
+            extn_id: AcceptableResponses::OID,
+            critical: false,
+            extn_value: OctetString::new(acceptable_responses.to_der().unwrap()).unwrap(),
+        }
+    }
+
+    fn tbs_request_with_extensions(request_extensions: Option<Extensions>) -> TbsRequest {
+        TbsRequest {
+            version: 0,
+            requestor_name: None,
+            request_list: Vec::new(),
+            request_extensions,
+        }
+    }
+
+    fn request_with_extensions(single_request_extensions: Option<Extensions>) -> Request {
+        Request {
+            req_cert: CertId {
+                hash_algorithm: AlgorithmIdentifierOwned {
+                    oid: const_oid::db::rfc5912::ID_SHA_1,
+                    parameters: None,
+                },
+                issuer_name_hash: OctetString::new(vec![0u8; 20]).unwrap(),
+                issuer_key_hash: OctetString::new(vec![0u8; 20]).unwrap(),
+                serial_number: x509_cert::serial_number::SerialNumber::new(&[0x01]).unwrap(),
+            },
+            single_request_extensions,
+        }
+    }
+
+    #[test]
+    fn get_extension_absent() {
+        let tbs = tbs_request_with_extensions(None);
+        assert_eq!(tbs.get_extension::<Nonce>(), Ok(None));
+    }
+
+    #[test]
+    fn get_extension_present() {
+        let tbs = tbs_request_with_extensions(Some(vec![nonce_extension(0xaa)]));
+        let nonce = tbs.get_extension::<Nonce>().unwrap().unwrap();
+        assert_eq!(nonce.0.as_bytes(), [0xaa; 4]);
+    }
+
+    #[test]
+    fn get_extension_duplicate() {
+        let tbs = tbs_request_with_extensions(Some(vec![
+            nonce_extension(0xaa),
+            nonce_extension(0xbb),
+        ]));
+        assert_eq!(tbs.get_extension::<Nonce>(), Err(Error::DuplicateExtension));
+    }
+
+    #[test]
+    fn request_get_extension_absent() {
+        let request = request_with_extensions(None);
+        assert_eq!(request.get_extension::<Nonce>(), Ok(None));
+    }
+
+    #[test]
+    fn request_get_extension_present() {
+        let request = request_with_extensions(Some(vec![nonce_extension(0xaa)]));
+        let nonce = request.get_extension::<Nonce>().unwrap().unwrap();
+        assert_eq!(nonce.0.as_bytes(), [0xaa; 4]);
+    }
+
+    #[test]
+    fn request_get_extension_duplicate() {
+        let request = request_with_extensions(Some(vec![
+            nonce_extension(0xaa),
+            nonce_extension(0xbb),
+        ]));
+        assert_eq!(
+            request.get_extension::<Nonce>(),
+            Err(Error::DuplicateExtension)
+        );
+    }
+
+    #[test]
+    fn acceptable_responses_duplicate() {
+        let tbs = tbs_request_with_extensions(Some(vec![
+            acceptable_responses_extension(),
+            acceptable_responses_extension(),
+        ]));
+        assert_eq!(tbs.acceptable_responses(), Err(Error::DuplicateExtension));
+    }
+
+    #[test]
+    fn check_duplicate_extensions_ok() {
+        let extensions = vec![nonce_extension(0xaa)];
+        assert_eq!(check_duplicate_extensions(&extensions), Ok(()));
+    }
+
+    #[test]
+    fn check_duplicate_extensions_detects_duplicate() {
+        let extensions = vec![nonce_extension(0xaa), nonce_extension(0xbb)];
+        assert_eq!(
+            check_duplicate_extensions(&extensions),
+            Err(Error::DuplicateExtension)
+        );
+    }
+}
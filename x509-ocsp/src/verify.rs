@@ -0,0 +1,235 @@
+//! `optionalSignature` verification
+//!
+//! The `signatureAlgorithm` OID on the [`Signature`] selects a concrete digest/verifier pair,
+//! which is then run against the DER encoding of the `tbsRequest`.
+
+use crate::{Error, OcspRequest, Result};
+use const_oid::ObjectIdentifier;
+use der::Encode;
+use digest::Digest;
+use ecdsa::signature::Verifier;
+use pkcs8::DecodePublicKey;
+use rsa::{pkcs1v15::Pkcs1v15Sign, RsaPublicKey};
+use sha2::{Sha256, Sha384, Sha512};
+use spki::SubjectPublicKeyInfoOwned;
+
+const RSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+const RSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.12");
+const RSA_WITH_SHA512: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.13");
+const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+const ECDSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+
+impl OcspRequest {
+    /// Verifies the `optionalSignature` over `tbsRequest`.
+    ///
+    /// If `public_key` is `None`, the signer's public key is taken from the first entry of the
+    /// signature's `certs` chain. Supported algorithms are RSA PKCS#1 v1.5 with SHA-256/384/512
+    /// and ECDSA with P-256/P-384. Returns [`Error::MissingSignature`] if there is no
+    /// `optionalSignature`, [`Error::MissingPublicKey`] if no public key is available,
+    /// [`Error::InvalidPublicKey`] if the available key doesn't parse as the claimed algorithm's
+    /// key type, and [`Error::UnsupportedAlgorithm`] for any other `signatureAlgorithm` OID.
+    pub fn verify_signature(&self, public_key: Option<&SubjectPublicKeyInfoOwned>) -> Result<()> {
+        let signature = self
+            .optional_signature
+            .as_ref()
+            .ok_or(Error::MissingSignature)?;
+
+        let spki = match public_key {
+            Some(spki) => spki,
+            None => signature
+                .certs
+                .as_ref()
+                .and_then(|certs| certs.first())
+                .map(|cert| &cert.tbs_certificate.subject_public_key_info)
+                .ok_or(Error::MissingPublicKey)?,
+        };
+
+        let message = self.tbs_request.to_der()?;
+        let sig_bytes = signature
+            .signature
+            .as_bytes()
+            .ok_or(Error::InvalidSignature)?;
+        let spki_der = spki.to_der()?;
+
+        match signature.signature_algorithm.oid {
+            RSA_WITH_SHA256 => verify_rsa::<Sha256>(&spki_der, &message, sig_bytes),
+            RSA_WITH_SHA384 => verify_rsa::<Sha384>(&spki_der, &message, sig_bytes),
+            RSA_WITH_SHA512 => verify_rsa::<Sha512>(&spki_der, &message, sig_bytes),
+            ECDSA_WITH_SHA256 => verify_ecdsa_p256(&spki_der, &message, sig_bytes),
+            ECDSA_WITH_SHA384 => verify_ecdsa_p384(&spki_der, &message, sig_bytes),
+            _ => Err(Error::UnsupportedAlgorithm),
+        }
+    }
+}
+
+fn verify_rsa<D: Digest + const_oid::AssociatedOid>(
+    spki_der: &[u8],
+    message: &[u8],
+    sig_bytes: &[u8],
+) -> Result<()> {
+    let public_key =
+        RsaPublicKey::from_public_key_der(spki_der).map_err(|_| Error::InvalidPublicKey)?;
+    let hashed = D::digest(message);
+
+    public_key
+        .verify(Pkcs1v15Sign::new::<D>(), &hashed, sig_bytes)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+fn verify_ecdsa_p256(spki_der: &[u8], message: &[u8], sig_bytes: &[u8]) -> Result<()> {
+    let verifying_key = p256::ecdsa::VerifyingKey::from_public_key_der(spki_der)
+        .map_err(|_| Error::InvalidPublicKey)?;
+    let signature =
+        p256::ecdsa::DerSignature::from_bytes(sig_bytes).map_err(|_| Error::InvalidSignature)?;
+
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+fn verify_ecdsa_p384(spki_der: &[u8], message: &[u8], sig_bytes: &[u8]) -> Result<()> {
+    let verifying_key = p384::ecdsa::VerifyingKey::from_public_key_der(spki_der)
+        .map_err(|_| Error::InvalidPublicKey)?;
+    let signature =
+        p384::ecdsa::DerSignature::from_bytes(sig_bytes).map_err(|_| Error::InvalidSignature)?;
+
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RSA_WITH_SHA256, RSA_WITH_SHA384};
+    use crate::{Error, OcspRequest, Signature, TbsRequest};
+    use alloc::vec::Vec;
+    use der::{asn1::BitString, Decode};
+    use digest::Digest;
+    use pkcs8::EncodePublicKey;
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::SeedableRng;
+    use rsa::{pkcs1v15::Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey};
+    use sha2::Sha256;
+    use spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+
+    fn empty_tbs_request() -> TbsRequest {
+        TbsRequest {
+            version: 0,
+            requestor_name: None,
+            request_list: Vec::new(),
+            request_extensions: None,
+        }
+    }
+
+    fn rsa_sha256_request() -> (OcspRequest, SubjectPublicKeyInfoOwned, Vec<u8>) {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let private_key = RsaPrivateKey::new(&mut rng, 2048).expect("key generation");
+        let public_key = RsaPublicKey::from(&private_key);
+        let spki_der = public_key.to_public_key_der().expect("spki encoding");
+        let spki = SubjectPublicKeyInfoOwned::from_der(spki_der.as_bytes()).expect("spki decode");
+
+        let tbs_request = empty_tbs_request();
+        let message = der::Encode::to_der(&tbs_request).expect("tbs encoding");
+        let hashed = Sha256::digest(&message);
+        let sig_bytes = private_key
+            .sign(Pkcs1v15Sign::new::<Sha256>(), &hashed)
+            .expect("signing");
+
+        let request = OcspRequest {
+            tbs_request,
+            optional_signature: Some(Signature {
+                signature_algorithm: AlgorithmIdentifierOwned {
+                    oid: RSA_WITH_SHA256,
+                    parameters: None,
+                },
+                signature: BitString::from_bytes(&sig_bytes).expect("bit string"),
+                certs: None,
+            }),
+        };
+
+        (request, spki, sig_bytes)
+    }
+
+    #[test]
+    fn verify_signature_valid() {
+        let (request, spki, _) = rsa_sha256_request();
+        assert_eq!(request.verify_signature(Some(&spki)), Ok(()));
+    }
+
+    #[test]
+    fn verify_signature_tampered() {
+        let (mut request, spki, sig_bytes) = rsa_sha256_request();
+        let mut tampered = sig_bytes;
+        tampered[0] ^= 0xff;
+        request.optional_signature.as_mut().unwrap().signature =
+            BitString::from_bytes(&tampered).unwrap();
+
+        assert_eq!(
+            request.verify_signature(Some(&spki)),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn verify_signature_missing_public_key() {
+        let (request, _, _) = rsa_sha256_request();
+        assert_eq!(request.verify_signature(None), Err(Error::MissingPublicKey));
+    }
+
+    #[test]
+    fn verify_signature_invalid_public_key() {
+        let (request, spki, _) = rsa_sha256_request();
+        let malformed_spki = SubjectPublicKeyInfoOwned {
+            algorithm: spki.algorithm,
+            subject_public_key: BitString::from_bytes(&[0xff; 4]).expect("bit string"),
+        };
+
+        assert_eq!(
+            request.verify_signature(Some(&malformed_spki)),
+            Err(Error::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn verify_signature_wrong_algorithm() {
+        let (mut request, spki, _) = rsa_sha256_request();
+        request
+            .optional_signature
+            .as_mut()
+            .unwrap()
+            .signature_algorithm
+            .oid = RSA_WITH_SHA384;
+
+        assert_eq!(
+            request.verify_signature(Some(&spki)),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn verify_signature_unsupported_algorithm() {
+        let (mut request, spki, _) = rsa_sha256_request();
+        request
+            .optional_signature
+            .as_mut()
+            .unwrap()
+            .signature_algorithm
+            .oid = const_oid::ObjectIdentifier::new_unwrap("1.2.3.4.5.6.7.8.9");
+
+        assert_eq!(
+            request.verify_signature(Some(&spki)),
+            Err(Error::UnsupportedAlgorithm)
+        );
+    }
+
+    #[test]
+    fn verify_signature_missing_signature() {
+        let request = empty_tbs_request();
+        let request = OcspRequest {
+            tbs_request: request,
+            optional_signature: None,
+        };
+
+        assert_eq!(request.verify_signature(None), Err(Error::MissingSignature));
+    }
+}